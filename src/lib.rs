@@ -17,8 +17,10 @@ pub const REQUEST_MEASUREMENT: u8 = 0xAC;
 pub const REQUEST_PRESSURE_MODE: u8 = 0x12;
 pub const REQUEST_MIN_PRESSURE: u8 = 0x13;
 pub const REQUEST_MAX_PRESSURE: u8 = 0x15;
+pub const REQUEST_SERIAL: u8 = 0x50;
 
 const READ_DELAY: u32 = 10; // Milliseconds
+const MAX_POLL_ATTEMPTS: u32 = 100;
 
 pub struct KellerLD<I2C, D> {
     i2c: I2C,
@@ -27,6 +29,72 @@ pub struct KellerLD<I2C, D> {
     pub pressure_mode: Option<PressureMode>,
     pub max_pressure: Option<f32>,
     pub min_pressure: Option<f32>,
+    oversampling: Oversampling,
+    checksum_mode: ChecksumMode,
+}
+
+/// Number of samples averaged together by [`KellerLD::read`] to reduce noise,
+/// at the cost of a proportionally longer conversion time.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub enum Oversampling {
+    #[default]
+    X1,
+    X2,
+    X4,
+    X8,
+    X16,
+}
+
+impl Oversampling {
+    fn samples(self) -> u32 {
+        match self {
+            Oversampling::X1 => 1,
+            Oversampling::X2 => 2,
+            Oversampling::X4 => 4,
+            Oversampling::X8 => 8,
+            Oversampling::X16 => 16,
+        }
+    }
+}
+
+/// Whether [`KellerLD`] cross-checks each measurement against a second, independent read.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub enum ChecksumMode {
+    #[default]
+    Disabled,
+    /// Take a second measurement and return [`KellerLDError::ChecksumMismatch`]
+    /// if its raw pressure and temperature words differ from the first. No
+    /// checksum is computed over the data: corruption that repeats identically
+    /// in both reads passes, and a reading that genuinely changes between the
+    /// two measurements is reported as a mismatch.
+    Retry,
+}
+
+/// Raw status byte returned with every transaction, decoded into its
+/// individual fields: `busy`, the raw 2-bit `mode` field, the sensor's own
+/// `checksum_error` flag, and `power_on_reset`.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Format)]
+pub struct Status {
+    pub busy: bool,
+    pub mode: u8,
+    pub checksum_error: bool,
+    pub power_on_reset: bool,
+}
+
+impl Status {
+    const BUSY_BIT: u8 = 1 << 5;
+    const MODE_MASK: u8 = 0b11 << 3;
+    const CHECKSUM_BIT: u8 = 1 << 2;
+    const POWER_ON_RESET_BIT: u8 = 1 << 7;
+
+    fn from_byte(byte: u8) -> Self {
+        Status {
+            busy: byte & Self::BUSY_BIT != 0,
+            mode: (byte & Self::MODE_MASK) >> 3,
+            checksum_error: byte & Self::CHECKSUM_BIT != 0,
+            power_on_reset: byte & Self::POWER_ON_RESET_BIT != 0,
+        }
+    }
 }
 
 #[derive(Error, Debug, Format)]
@@ -37,12 +105,12 @@ pub enum KellerLDError {
     Bus(embedded_hal::i2c::ErrorKind),
     #[error("must get calibration info before use")]
     Uncalibrated,
-    #[error("wait for measurement")]
-    Busy,
     #[error("sensor is not in 'normal mode'")]
     IncorrectMode,
     #[error("checksum mismatch")]
     ChecksumMismatch,
+    #[error("measurement did not complete within the retry budget")]
+    Timeout,
 }
 
 // Convert I²C errors
@@ -51,15 +119,55 @@ impl<E: embedded_hal::i2c::Error> From<E> for KellerLDError {
         KellerLDError::Bus(e.kind())
     }
 }
+#[derive(Debug)]
 pub struct Measurement {
     pub temperature: Celcius,
     pub pressure: Bar,
+    pub status: Status,
+}
+
+/// Fluid density and local gravity used to convert pressure into depth.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct DepthParams {
+    pub density_kg_m3: f32,
+    pub gravity_m_s2: f32,
+}
+
+impl DepthParams {
+    pub const FRESH_WATER: DepthParams = DepthParams {
+        density_kg_m3: 1000.0,
+        gravity_m_s2: 9.81,
+    };
+
+    pub const SEAWATER: DepthParams = DepthParams {
+        density_kg_m3: 1025.0,
+        gravity_m_s2: 9.81,
+    };
 }
 
 impl Measurement {
     pub fn depth_underwater(&self) -> Metre {
         100.0 * (self.pressure - ATMOSPHERIC_PRESSURE) / 9.81
     }
+
+    /// Depth below the surface, given fluid density and local gravity.
+    ///
+    /// `pressure` is in bar; the result converts via `1e5 * (pressure - ATMOSPHERIC_PRESSURE) / (density * gravity)`.
+    /// See [`Self::depth_underwater`] for the fresh-water, `g = 9.81` shortcut.
+    pub fn depth(&self, params: DepthParams) -> Metre {
+        1.0e5 * (self.pressure - ATMOSPHERIC_PRESSURE) / (params.density_kg_m3 * params.gravity_m_s2)
+    }
+
+    /// Altitude above sea level, using the international barometric formula.
+    ///
+    /// `sea_level` is the local QNH; pass [`ATMOSPHERIC_PRESSURE`] if it is
+    /// unknown. This is only meaningful when the sensor is reading absolute
+    /// pressure in air: a vented or sealed reading does not represent true
+    /// atmospheric pressure, so the result will not be physically meaningful
+    /// in those modes.
+    pub fn altitude_above_sea_level(&self, sea_level: Bar) -> Metre {
+        44330.0 * (1.0 - libm::powf(self.pressure / sea_level, 1.0 / 5.255))
+    }
 }
 
 #[derive(Debug, PartialEq, Format)]
@@ -99,9 +207,36 @@ where
             pressure_mode: None,
             max_pressure: None,
             min_pressure: None,
+            oversampling: Oversampling::default(),
+            checksum_mode: ChecksumMode::default(),
         }
     }
 
+    /// Configure how many samples [`Self::read`] averages together. Defaults
+    /// to [`Oversampling::X1`], preserving the previous single-sample timing.
+    pub fn set_oversampling(&mut self, oversampling: Oversampling) {
+        self.oversampling = oversampling;
+    }
+
+    /// Configure whether [`Self::read`] and [`Self::read_status`] cross-check
+    /// each measurement against a second read. See [`ChecksumMode`] for what
+    /// this does and does not catch.
+    pub fn set_checksum_mode(&mut self, checksum_mode: ChecksumMode) {
+        self.checksum_mode = checksum_mode;
+    }
+
+    /// Read and decode the status byte from a fresh measurement.
+    ///
+    /// Unlike [`Self::read`], this does not reject a non-zero mode field or
+    /// the sensor's checksum-error bit: it reports whatever the status byte
+    /// says, so callers can use it to diagnose *why* [`Self::read`] is
+    /// failing. It waits for the conversion to finish, so `busy` is always
+    /// `false` in the returned status.
+    pub fn read_status(&mut self) -> Result<Status, KellerLDError> {
+        let (status, _, _) = self._measure_once()?;
+        Ok(status)
+    }
+
     pub fn get_calibration(&mut self) -> Result<Date, KellerLDError> {
         let date = self.get_pressure_mode()?;
         self.get_min_pressure()?;
@@ -110,27 +245,152 @@ where
         Ok(date)
     }
 
-    pub fn read(&mut self) -> Result<Measurement, KellerLDError> {
-        let mut data = [0; 5];
-        self._read_write(&[REQUEST_MEASUREMENT], &mut data)?;
+    /// Issue the measurement request and return immediately, without waiting
+    /// for the conversion to finish. Pair with [`Self::try_read`] to poll for
+    /// the result, or use [`Self::read`] for the blocking convenience wrapper.
+    pub fn start_measurement(&mut self) -> Result<(), KellerLDError> {
+        self.i2c.write(self.address, &[REQUEST_MEASUREMENT])?;
+        Ok(())
+    }
+
+    /// Poll for the result of a measurement started with [`Self::start_measurement`].
+    ///
+    /// Returns `Err(nb::Error::WouldBlock)` while the sensor's busy bit is
+    /// still set, so callers can interleave other work during the conversion
+    /// window instead of blocking on a fixed delay.
+    pub fn try_read(&mut self) -> nb::Result<Measurement, KellerLDError> {
+        let (status, raw_pressure, raw_temperature) = self._try_read_raw()?;
+        Self::_validate_status(status).map_err(nb::Error::Other)?;
+        Ok(Measurement {
+            temperature: self._convert_temperature(raw_temperature),
+            pressure: self
+                ._convert_pressure(raw_pressure)
+                .map_err(nb::Error::Other)?,
+            status,
+        })
+    }
 
-        let status = data[0];
-        if status & 1 << 5 != 0 {
-            return Err(KellerLDError::Busy);
+    pub fn read(&mut self) -> Result<Measurement, KellerLDError> {
+        let samples = self.oversampling.samples();
+        let mut pressure_sum: u32 = 0;
+        let mut temperature_sum: u32 = 0;
+        let mut status = Status::default();
+
+        for _ in 0..samples {
+            let (sample_status, raw_pressure, raw_temperature) = self._measure_once()?;
+            Self::_validate_status(sample_status)?;
+            pressure_sum += raw_pressure as u32;
+            temperature_sum += raw_temperature as u32;
+            status = sample_status;
         }
-        if status & 0b11 << 3 != 0 {
+
+        let raw_pressure = (pressure_sum / samples) as u16;
+        let raw_temperature = (temperature_sum / samples) as u16;
+        Ok(Measurement {
+            temperature: self._convert_temperature(raw_temperature),
+            pressure: self._convert_pressure(raw_pressure)?,
+            status,
+        })
+    }
+
+    /// Reject a status whose mode bits or checksum-error flag indicate the
+    /// accompanying pressure/temperature words should not be trusted.
+    fn _validate_status(status: Status) -> Result<(), KellerLDError> {
+        if status.mode != 0 {
             return Err(KellerLDError::IncorrectMode);
         }
-        if status & 1 << 2 != 0 {
+        if status.checksum_error {
             return Err(KellerLDError::ChecksumMismatch);
         }
+        Ok(())
+    }
+
+    /// Start a measurement and block until it completes, returning the
+    /// decoded status and the raw pressure/temperature words. Used by
+    /// [`Self::read`] to average multiple samples when oversampling is
+    /// enabled.
+    ///
+    /// When [`ChecksumMode::Retry`] is enabled, takes a second measurement
+    /// and requires it to agree with the first, surfacing
+    /// [`KellerLDError::ChecksumMismatch`] if the bus produced two different
+    /// answers for what should be the same reading.
+    fn _measure_once(&mut self) -> Result<(Status, u16, u16), KellerLDError> {
+        let first = self._measure_raw_once()?;
+
+        if self.checksum_mode == ChecksumMode::Retry {
+            let second = self._measure_raw_once()?;
+            if (second.1, second.2) != (first.1, first.2) {
+                return Err(KellerLDError::ChecksumMismatch);
+            }
+        }
+
+        Ok(first)
+    }
+
+    /// Poll [`Self::_try_read_raw`] until it stops reporting busy, waiting
+    /// [`READ_DELAY`] between attempts and giving up with
+    /// [`KellerLDError::Timeout`] after [`MAX_POLL_ATTEMPTS`], so a sensor
+    /// whose busy bit never clears (dead sensor, bus glitch, miswiring)
+    /// cannot hang the caller indefinitely.
+    fn _measure_raw_once(&mut self) -> Result<(Status, u16, u16), KellerLDError> {
+        self.start_measurement()?;
+
+        for _ in 0..MAX_POLL_ATTEMPTS {
+            self.delay.delay_ms(READ_DELAY);
+            match self._try_read_raw() {
+                Ok(raw) => return Ok(raw),
+                Err(nb::Error::WouldBlock) => continue,
+                Err(nb::Error::Other(e)) => return Err(e),
+            }
+        }
+
+        Err(KellerLDError::Timeout)
+    }
+
+    /// Read the raw status byte and data words. Only the busy bit gates this
+    /// read (the pressure/temperature words aren't valid yet); mode and
+    /// checksum errors are left for the caller to decide whether to act on,
+    /// via [`Self::_validate_status`] or by inspecting [`Status`] directly.
+    fn _try_read_raw(&mut self) -> nb::Result<(Status, u16, u16), KellerLDError> {
+        let mut data = [0; 5];
+        self.i2c
+            .read(self.address, &mut data)
+            .map_err(KellerLDError::from)?;
+
+        let status_byte = data[0];
+        if status_byte & Status::BUSY_BIT != 0 {
+            return Err(nb::Error::WouldBlock);
+        }
 
         let raw_pressure = u16::from_be_bytes(data[1..3].try_into().unwrap());
         let raw_temperature = u16::from_be_bytes(data[3..5].try_into().unwrap());
-        Ok(Measurement {
-            temperature: self._convert_temperature(raw_temperature),
-            pressure: self._convert_pressure(raw_pressure)?,
-        })
+
+        Ok((Status::from_byte(status_byte), raw_pressure, raw_temperature))
+    }
+
+    /// Confirm a sensor is present and responding before taking measurements.
+    ///
+    /// Performs the same lightweight transaction as [`Self::get_pressure_mode`]
+    /// and checks the response is plausible, so callers on a shared I²C bus can
+    /// detect a missing or mis-wired sensor instead of getting garbage from
+    /// [`Self::read`].
+    pub fn probe(&mut self) -> Result<(), KellerLDError> {
+        self.get_pressure_mode()?;
+        Ok(())
+    }
+
+    /// Read the Keller device serial number (registers 0x50-0x51).
+    pub fn get_serial(&mut self) -> Result<u32, KellerLDError> {
+        let mut bytes = [0; 4];
+
+        let mut data = [0; 3];
+        self._read_write(&[REQUEST_SERIAL], &mut data)?;
+        bytes[0..2].copy_from_slice(&data[1..3]);
+
+        self._read_write(&[REQUEST_SERIAL + 1], &mut data)?;
+        bytes[2..4].copy_from_slice(&data[1..3]);
+
+        Ok(u32::from_be_bytes(bytes))
     }
 
     pub fn get_pressure_mode(&mut self) -> Result<Date, KellerLDError> {
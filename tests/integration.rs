@@ -4,7 +4,10 @@ use embedded_hal::i2c::{Mock as I2cMock, Transaction};
 
 use float_eq::assert_float_eq;
 
-use keller_ld::{Date, KellerLD, DEFAULT_ADDR, PressureMode};
+use keller_ld::{
+    ChecksumMode, Date, DepthParams, KellerLD, KellerLDError, Oversampling, DEFAULT_ADDR,
+    PressureMode,
+};
 
 // See https://www.kelleramerica.com/file-cache/website_component/5e2f22709d8b1060a188c35e/manuals/1580321559752
 
@@ -186,6 +189,143 @@ fn read_pressure_paa_9ld_success() {
     keller_ld.destroy().done();
 }
 
+#[test]
+fn probe_success() {
+    let expectations = [
+        Transaction::write(0x40, vec![0x12]),
+        Transaction::read(0x40, vec![0x40, 0b00010101, 0b01110100]),
+    ];
+    let mock = I2cMock::new(&expectations);
+
+    let mut keller_ld = KellerLD::new(mock, DEFAULT_ADDR, DelayMock);
+    keller_ld.probe().unwrap();
+
+    keller_ld.destroy().done();
+}
+
+#[test]
+fn probe_failure() {
+    let expectations = [
+        Transaction::write(0x40, vec![0x12]),
+        Transaction::read(0x40, vec![0x40, 0b00010101, 0b01110111]),
+    ];
+    let mock = I2cMock::new(&expectations);
+
+    let mut keller_ld = KellerLD::new(mock, DEFAULT_ADDR, DelayMock);
+    let err = keller_ld.probe().unwrap_err();
+
+    assert!(matches!(err, KellerLDError::UnexpectedValue));
+
+    keller_ld.destroy().done();
+}
+
+#[test]
+fn get_serial_success() {
+    let expectations = [
+        Transaction::write(0x40, vec![0x50]),
+        Transaction::read(0x40, vec![0x40, 0x00, 0x01]),
+        Transaction::write(0x40, vec![0x51]),
+        Transaction::read(0x40, vec![0x40, 0x02, 0x03]),
+    ];
+    let mock = I2cMock::new(&expectations);
+
+    let mut keller_ld = KellerLD::new(mock, DEFAULT_ADDR, DelayMock);
+    let serial = keller_ld.get_serial().unwrap();
+
+    assert_eq!(serial, 0x0001_0203);
+
+    keller_ld.destroy().done();
+}
+
+#[test]
+fn altitude_above_sea_level_at_sea_level() {
+    let measurement = keller_ld::Measurement {
+        temperature: 20.0,
+        pressure: keller_ld::ATMOSPHERIC_PRESSURE,
+        status: keller_ld::Status::default(),
+    };
+
+    assert_float_eq!(
+        measurement.altitude_above_sea_level(keller_ld::ATMOSPHERIC_PRESSURE),
+        0.0,
+        abs <= 1.0E-3
+    );
+}
+
+#[test]
+fn depth_matches_depth_underwater_for_fresh_water() {
+    let measurement = keller_ld::Measurement {
+        temperature: 20.0,
+        pressure: 2.0 * keller_ld::ATMOSPHERIC_PRESSURE,
+        status: keller_ld::Status::default(),
+    };
+
+    assert_float_eq!(
+        measurement.depth(DepthParams::FRESH_WATER),
+        measurement.depth_underwater(),
+        abs <= 1.0E-6
+    );
+}
+
+#[test]
+fn depth_is_shallower_in_seawater() {
+    let measurement = keller_ld::Measurement {
+        temperature: 20.0,
+        pressure: 2.0 * keller_ld::ATMOSPHERIC_PRESSURE,
+        status: keller_ld::Status::default(),
+    };
+
+    assert!(measurement.depth(DepthParams::SEAWATER) < measurement.depth(DepthParams::FRESH_WATER));
+}
+
+#[test]
+fn try_read_would_block() {
+    let expectations = [
+        Transaction::write(0x40, vec![0xAC]),
+        Transaction::read(0x40, vec![0b0010_0000, 0x00, 0x00, 0x00, 0x00]),
+    ];
+    let mock = I2cMock::new(&expectations);
+
+    let mut keller_ld = KellerLD::new(mock, DEFAULT_ADDR, DelayMock);
+    keller_ld.pressure_mode = Some(PressureMode::Absolute);
+    keller_ld.min_pressure = Some(-1.0);
+    keller_ld.max_pressure = Some(10.0);
+
+    keller_ld.start_measurement().unwrap();
+    let result = keller_ld.try_read();
+
+    assert!(matches!(result, Err(nb::Error::WouldBlock)));
+
+    keller_ld.destroy().done();
+}
+
+#[test]
+fn read_oversampled_averages_raw_samples() {
+    let expectations = [
+        Transaction::write(0x40, vec![0xAC]),
+        Transaction::read(0x40, vec![0x40, 0x4E, 0x1F, 0x5D, 0xD1]),
+        Transaction::write(0x40, vec![0xAC]),
+        Transaction::read(0x40, vec![0x40, 0x4E, 0x21, 0x5D, 0xD1]),
+    ];
+    let mock = I2cMock::new(&expectations);
+
+    let mut keller_ld = KellerLD::new(mock, DEFAULT_ADDR, DelayMock);
+    keller_ld.pressure_mode = Some(PressureMode::Absolute);
+    keller_ld.min_pressure = Some(-1.0);
+    keller_ld.max_pressure = Some(10.0);
+    keller_ld.set_oversampling(Oversampling::X2);
+
+    let measurement = keller_ld.read().unwrap();
+
+    assert_float_eq!(
+        measurement.pressure,
+        0.213867,
+        abs <= 1.0E-6
+    );
+
+    keller_ld.destroy().done();
+}
+
 #[test]
 fn read_temperature_success() {
     let expectations = [
@@ -207,5 +347,112 @@ fn read_temperature_success() {
         abs <= 1.0E-2
     );
 
+    keller_ld.destroy().done();
+}
+
+#[test]
+fn read_status_waits_until_not_busy() {
+    let expectations = [
+        Transaction::write(0x40, vec![0xAC]),
+        Transaction::read(0x40, vec![0b0010_0000, 0x00, 0x00, 0x00, 0x00]),
+        Transaction::read(0x40, vec![0x40, 0x4E, 0x20, 0x5D, 0xD1]),
+    ];
+    let mock = I2cMock::new(&expectations);
+
+    let mut keller_ld = KellerLD::new(mock, DEFAULT_ADDR, DelayMock);
+    keller_ld.pressure_mode = Some(PressureMode::Absolute);
+    keller_ld.min_pressure = Some(-1.0);
+    keller_ld.max_pressure = Some(10.0);
+
+    let status = keller_ld.read_status().unwrap();
+
+    assert!(!status.busy);
+    assert!(!status.checksum_error);
+    assert!(!status.power_on_reset);
+
+    keller_ld.destroy().done();
+}
+
+#[test]
+fn read_status_reports_mode_and_power_on_reset() {
+    let expectations = [
+        Transaction::write(0x40, vec![0xAC]),
+        Transaction::read(0x40, vec![0b1000_1000, 0x4E, 0x20, 0x5D, 0xD1]),
+    ];
+    let mock = I2cMock::new(&expectations);
+
+    let mut keller_ld = KellerLD::new(mock, DEFAULT_ADDR, DelayMock);
+
+    let status = keller_ld.read_status().unwrap();
+
+    assert!(!status.busy);
+    assert_eq!(status.mode, 1);
+    assert!(!status.checksum_error);
+    assert!(status.power_on_reset);
+
+    keller_ld.destroy().done();
+}
+
+#[test]
+fn read_rejects_incorrect_mode() {
+    let expectations = [
+        Transaction::write(0x40, vec![0xAC]),
+        Transaction::read(0x40, vec![0b0000_1000, 0x4E, 0x20, 0x5D, 0xD1]),
+    ];
+    let mock = I2cMock::new(&expectations);
+
+    let mut keller_ld = KellerLD::new(mock, DEFAULT_ADDR, DelayMock);
+    keller_ld.pressure_mode = Some(PressureMode::Absolute);
+    keller_ld.min_pressure = Some(-1.0);
+    keller_ld.max_pressure = Some(10.0);
+
+    let err = keller_ld.read().unwrap_err();
+
+    assert!(matches!(err, KellerLDError::IncorrectMode));
+
+    keller_ld.destroy().done();
+}
+
+#[test]
+fn read_with_retry_checksum_success() {
+    let expectations = [
+        Transaction::write(0x40, vec![0xAC]),
+        Transaction::read(0x40, vec![0x40, 0x4E, 0x20, 0x5D, 0xD1]),
+        Transaction::write(0x40, vec![0xAC]),
+        Transaction::read(0x40, vec![0x40, 0x4E, 0x20, 0x5D, 0xD1]),
+    ];
+    let mock = I2cMock::new(&expectations);
+
+    let mut keller_ld = KellerLD::new(mock, DEFAULT_ADDR, DelayMock);
+    keller_ld.pressure_mode = Some(PressureMode::Absolute);
+    keller_ld.min_pressure = Some(-1.0);
+    keller_ld.max_pressure = Some(10.0);
+    keller_ld.set_checksum_mode(ChecksumMode::Retry);
+
+    keller_ld.read().unwrap();
+
+    keller_ld.destroy().done();
+}
+
+#[test]
+fn read_with_retry_checksum_mismatch() {
+    let expectations = [
+        Transaction::write(0x40, vec![0xAC]),
+        Transaction::read(0x40, vec![0x40, 0x4E, 0x20, 0x5D, 0xD1]),
+        Transaction::write(0x40, vec![0xAC]),
+        Transaction::read(0x40, vec![0x40, 0x4E, 0x20, 0x5D, 0xD0]),
+    ];
+    let mock = I2cMock::new(&expectations);
+
+    let mut keller_ld = KellerLD::new(mock, DEFAULT_ADDR, DelayMock);
+    keller_ld.pressure_mode = Some(PressureMode::Absolute);
+    keller_ld.min_pressure = Some(-1.0);
+    keller_ld.max_pressure = Some(10.0);
+    keller_ld.set_checksum_mode(ChecksumMode::Retry);
+
+    let err = keller_ld.read().unwrap_err();
+
+    assert!(matches!(err, KellerLDError::ChecksumMismatch));
+
     keller_ld.destroy().done();
 }
\ No newline at end of file